@@ -43,3 +43,142 @@ pub fn parse_edge(line: &str, undirected: bool) -> anyhow::Result<Vec<Edge>> {
         vec![Edge { from, to, weight }]
     })
 }
+
+/// A directed link between two nodes, used as the key of a [`PathSet`] trie edge.
+/// `N` is the node id type; the crate's `bhandari` uses this with `String` node names,
+/// once they have been restored from the graph's node indices.
+pub type Link<N> = (N, N);
+
+/// A set of paths stored as an edge-keyed prefix trie. Paths that share a leading
+/// segment share trie nodes, so common sub-routes are stored once and prefix or
+/// containment queries don't have to rescan every path vector. This is the shape
+/// `bhandari` uses to return its `k` disjoint paths compactly.
+#[derive(Debug)]
+pub struct PathSet<N> {
+    root: PathNode<N>,
+}
+
+impl<N> Default for PathSet<N> {
+    fn default() -> Self {
+        PathSet {
+            root: PathNode::default(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct PathNode<N> {
+    /// outgoing links to child nodes, each keyed by the link that reaches the child
+    children: Vec<(Link<N>, PathNode<N>)>,
+    /// total path cost, set when a stored path terminates at this node
+    cost: Option<i32>,
+}
+
+impl<N> Default for PathNode<N> {
+    fn default() -> Self {
+        PathNode {
+            children: Vec::new(),
+            cost: None,
+        }
+    }
+}
+
+impl<N: Clone + PartialEq> PathSet<N> {
+    /// Build a `PathSet` from paths paired with their real weighted cost, as computed
+    /// by `bhandari` over the original (unsplit, unreversed) edge weights.
+    pub fn from_paths(paths: &[(Vec<N>, i32)]) -> Self {
+        let mut set = PathSet::default();
+        for (path, cost) in paths {
+            let links = path
+                .windows(2)
+                .map(|link| (link[0].clone(), link[1].clone()))
+                .collect::<Vec<_>>();
+            set.insert(&links, *cost);
+        }
+        set
+    }
+
+    /// Insert a path, given as its sequence of links, recording `cost` at its end.
+    /// Walks the existing trie one link at a time, extending it where it diverges.
+    pub fn insert(&mut self, path_edges: &[Link<N>], cost: i32) {
+        let mut node = &mut self.root;
+        for link in path_edges {
+            let pos = node.children.iter().position(|(edge, _)| edge == link);
+            let pos = match pos {
+                Some(pos) => pos,
+                None => {
+                    node.children.push((link.clone(), PathNode::default()));
+                    node.children.len() - 1
+                }
+            };
+            node = &mut node.children[pos].1;
+        }
+        node.cost = Some(cost);
+    }
+
+    /// Reconstruct every stored path as a `(nodes, cost)` pair. A path stored with no
+    /// links (a single node) comes back with an empty node list.
+    pub fn paths(&self) -> Vec<(Vec<N>, i32)> {
+        let mut out = Vec::new();
+        collect_paths(&self.root, &mut Vec::new(), &mut out);
+        out
+    }
+
+    /// Returns whether some stored path begins with `prefix` (given as links).
+    pub fn contains_prefix(&self, prefix: &[Link<N>]) -> bool {
+        let mut node = &self.root;
+        for link in prefix {
+            match node.children.iter().find(|(edge, _)| edge == link) {
+                Some((_, child)) => node = child,
+                None => return false,
+            }
+        }
+        true
+    }
+}
+
+/// Depth-first walk accumulating the links on the way down; at each terminal node the
+/// accumulated links are turned back into a node sequence.
+fn collect_paths<N: Clone>(
+    node: &PathNode<N>,
+    links: &mut Vec<Link<N>>,
+    out: &mut Vec<(Vec<N>, i32)>,
+) {
+    if let Some(cost) = node.cost {
+        let mut nodes = Vec::with_capacity(links.len() + 1);
+        if let Some((from, _)) = links.first() {
+            nodes.push(from.clone());
+            nodes.extend(links.iter().map(|(_, to)| to.clone()));
+        }
+        out.push((nodes, cost));
+    }
+
+    for (link, child) in &node.children {
+        links.push(link.clone());
+        collect_paths(child, links, out);
+        links.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn path_set_roundtrips_and_answers_prefix_queries() {
+        let paths = vec![
+            (vec!["A".to_string(), "B".to_string(), "D".to_string()], 2),
+            (vec!["A".to_string(), "C".to_string(), "D".to_string()], 2),
+        ];
+        let set = PathSet::from_paths(&paths);
+
+        let mut roundtrip = set.paths();
+        roundtrip.sort();
+        let mut expected = paths;
+        expected.sort();
+        assert_eq!(roundtrip, expected);
+
+        assert!(set.contains_prefix(&[("A".to_string(), "B".to_string())]));
+        assert!(!set.contains_prefix(&[("A".to_string(), "X".to_string())]));
+    }
+}
@@ -1,11 +1,33 @@
-use clap::{arg, command, Parser};
+use clap::{Args as ClapArgs, Parser, Subcommand, ValueEnum};
 use pathfinding::prelude::*;
-use std::{collections::HashMap, fs, sync::Arc};
+use petgraph::graph::{Graph, NodeIndex};
+use petgraph::visit::{Data, EdgeRef, IntoEdges, NodeIndexable};
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, HashSet},
+    fs,
+    sync::Arc,
+};
 
 use anyhow::Context;
+use bhandari_rs::PathSet;
 
 #[derive(Debug, Clone, Parser)]
 #[command()]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum Command {
+    /// Find k disjoint or shortest paths between two nodes
+    Paths(Args),
+    /// Find a low-cost tree connecting a set of terminals
+    Steiner(SteinerArgs),
+}
+
+#[derive(Debug, Clone, ClapArgs)]
 struct Args {
     #[arg()]
     file: String,
@@ -22,78 +44,196 @@ struct Args {
     /// Treat links as undirected, false by default
     #[arg(short, long)]
     undirected: bool,
+
+    /// Find node-disjoint instead of link-disjoint paths
+    #[arg(short, long)]
+    node_disjoint: bool,
+
+    /// Search strategy: disjoint-path Bhandari or k-shortest-path Yen
+    #[arg(short, long, value_enum, default_value_t = Mode::Bhandari)]
+    mode: Mode,
 }
 
-fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
+/// Which k-path search the CLI runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Mode {
+    /// k link- (or node-) disjoint paths
+    Bhandari,
+    /// k shortest simple paths, which may overlap
+    Yen,
+}
+
+#[derive(Debug, Clone, ClapArgs)]
+struct SteinerArgs {
+    #[arg()]
+    file: String,
 
-    let edges =
-        load_edges_from_file(&args.file, args.undirected).context("loading edges from file")?;
+    /// Terminal nodes to connect
+    #[arg(required = true, num_args = 1..)]
+    terminals: Vec<String>,
 
-    let result =
-        bhandari(&edges, &args.start, &args.to, args.k).context("getting disjoint paths")?;
+    /// Treat links as undirected, false by default
+    #[arg(short, long)]
+    undirected: bool,
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Paths(args) => {
+            let edges = load_edges_from_file(&args.file, args.undirected)
+                .context("loading edges from file")?;
+
+            match args.mode {
+                Mode::Bhandari => {
+                    let result =
+                        bhandari(&edges, &args.start, &args.to, args.k, args.node_disjoint)
+                            .context("getting disjoint paths")?;
+                    println!("{:?}", result.paths());
+                }
+                Mode::Yen => {
+                    let result = yen(&edges, &args.start, &args.to, args.k)
+                        .context("getting k shortest paths")?;
+                    println!("{result:?}");
+                }
+            }
+        }
+        Command::Steiner(args) => {
+            let edges = load_edges_from_file(&args.file, args.undirected)
+                .context("loading edges from file")?;
+
+            let tree = steiner_tree(&edges, &args.terminals).context("building steiner tree")?;
 
-    println!("{result:?}");
+            let total: i32 = tree.iter().map(|edge| edge.weight).sum();
+            for edge in &tree {
+                println!("{} {} {}", edge.from, edge.weight, edge.to);
+            }
+            println!("total weight: {total}");
+        }
+    }
 
     Ok(())
 }
 
-fn bhandari(_graph: &[Edge], start: &str, end: &str, k: usize) -> anyhow::Result<Vec<Vec<String>>> {
-    struct Edge {
-        from: u32,
-        to: u32,
-        weight: i32,
+/// Build a petgraph graph from the crate's edge list, returning the graph and a map
+/// from node name to its `NodeIndex`.
+fn build_graph(edges: &[Edge]) -> (Graph<Arc<str>, i32>, HashMap<Arc<str>, NodeIndex>) {
+    let mut graph = Graph::<Arc<str>, i32>::new();
+    let mut indices: HashMap<Arc<str>, NodeIndex> = HashMap::new();
+
+    for edge in edges {
+        let from = *indices
+            .entry(edge.from.clone())
+            .or_insert_with(|| graph.add_node(edge.from.clone()));
+        let to = *indices
+            .entry(edge.to.clone())
+            .or_insert_with(|| graph.add_node(edge.to.clone()));
+        graph.add_edge(from, to, edge.weight);
     }
 
-    // convert string nodes to numbers
-    let mut nodes = _graph
-        .iter()
-        .map(|link| link.from.clone())
-        .chain(_graph.iter().map(|link| link.to.clone()))
-        .collect::<Vec<_>>();
-    nodes.sort();
-    nodes.dedup();
+    (graph, indices)
+}
 
-    let nodes_names_to_indices: HashMap<String, u32> = HashMap::from_iter(
-        nodes
-            .iter()
-            .enumerate()
-            .map(|(i, s)| (s.to_string(), u32::try_from(i).unwrap())),
-    );
-    let nodes_indices_to_names = nodes;
+fn bhandari(
+    graph_in: &[Edge],
+    start: &str,
+    end: &str,
+    k: usize,
+    node_disjoint: bool,
+) -> anyhow::Result<PathSet<String>> {
+    // For node-disjoint paths, split every internal node into an `_in`/`_out` pair
+    // joined by a zero-weight arc. Reversing that internal arc in a later round then
+    // forbids reuse of the node itself, reusing the same link-disjoint machinery.
+    let split_edges;
+    let _graph: &[_] = if node_disjoint {
+        split_edges = split_internal_nodes(graph_in, start, end);
+        &split_edges
+    } else {
+        graph_in
+    };
 
-    let graph = _graph
-        .iter()
-        .map(|edge| Edge {
-            from: *nodes_names_to_indices.get(&*edge.from).unwrap(),
-            to: *nodes_names_to_indices.get(&*edge.to).unwrap(),
-            weight: edge.weight,
-        })
-        .collect::<Vec<_>>();
+    // Build a petgraph graph from the edge list and delegate to the generic core, so
+    // successor lookups go through the graph's own adjacency rather than rescanning
+    // the edge vector on every step.
+    let (graph, indices) = build_graph(_graph);
 
-    let start = *nodes_names_to_indices.get(start).unwrap();
-    let end = *nodes_names_to_indices.get(end).unwrap();
+    let start = *indices
+        .get(start)
+        .context("this graph doesn't contain such path")?;
+    let end = *indices
+        .get(end)
+        .context("this graph doesn't contain such path")?;
 
-    // dijkstra calls a function at each step to get list of next nodes it goes to, so transform our
-    // edge list to lambda that returns `to` nodes for a given node
-    let shortest_path = {
-        let successors = |current_node: &u32| {
-            graph
-                .iter()
-                .filter(|edge| edge.from == *current_node)
-                .map(|&Edge { to, weight, .. }| (to, weight))
-                .collect::<Vec<_>>()
-        };
+    let paths = bhandari_generic(&graph, start, end, k)?;
 
-        // find shortest path P_1 from s to t
-        let (shortest_path, _cost) =
-            dijkstra(&start, successors, |current_node| *current_node == end)
-                .context("this graph doesn't contain such path")?;
+    // restore original node names
+    let paths: Vec<(Vec<String>, i32)> = paths
+        .into_iter()
+        .map(|(path, cost)| {
+            let path = path
+                .into_iter()
+                .map(|node| graph[node].to_string())
+                .collect::<Vec<_>>();
+            (path, cost)
+        })
+        .collect();
 
-        shortest_path
+    // collapse the split `_in`/`_out` nodes back to their original names; the
+    // zero-weight internal split arcs keep each path's cost unchanged
+    let paths = if node_disjoint {
+        paths
+            .into_iter()
+            .map(|(path, cost)| (collapse_split_path(path), cost))
+            .collect()
+    } else {
+        paths
     };
 
-    let mut paths: Vec<Vec<u32>> = Vec::with_capacity(k);
+    Ok(PathSet::from_paths(&paths))
+}
+
+/// Core Bhandari over any petgraph-style graph with `i32` edge weights. Returns the
+/// `k` link-disjoint paths from `start` to `end`, each paired with its real cost on
+/// the original (non-reversed) weights. Successor lookups go through the host graph's
+/// own adjacency via the visitor traits, so callers can pass a `petgraph::Graph` (or
+/// any compatible graph) directly.
+fn bhandari_generic<G>(
+    g: G,
+    start: G::NodeId,
+    end: G::NodeId,
+    k: usize,
+) -> anyhow::Result<Vec<(Vec<G::NodeId>, i32)>>
+where
+    G: IntoEdges + NodeIndexable + Data<EdgeWeight = i32>,
+{
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let n = g.node_bound();
+    let start = g.to_index(start);
+    let end = g.to_index(end);
+
+    // Snapshot the host adjacency once: the first search reads the adjacency list and
+    // later rounds mutate a working copy keyed by node index as arcs get reversed.
+    let mut adjacency: Vec<Vec<(usize, i32)>> = vec![Vec::new(); n];
+    let mut base: HashMap<(usize, usize), i32> = HashMap::new();
+    for (node, out_links) in adjacency.iter_mut().enumerate() {
+        for edge in g.edges(g.from_index(node)) {
+            let to = g.to_index(edge.target());
+            let weight = *edge.weight();
+            out_links.push((to, weight));
+            base.insert((node, to), weight);
+        }
+    }
+
+    // find shortest path P_1 from s to t on the non-negative original weights
+    let successors = |current_node: &usize| adjacency[*current_node].clone();
+    let (shortest_path, _cost) = dijkstra(&start, successors, |node| *node == end)
+        .context("this graph doesn't contain such path")?;
+
+    let mut paths: Vec<Vec<usize>> = Vec::with_capacity(k);
     paths.push(shortest_path);
 
     for _ in 0..(k - 1) {
@@ -101,8 +241,7 @@ fn bhandari(_graph: &[Edge], start: &str, end: &str, k: usize) -> anyhow::Result
         // we use link-disjoint, so skip
 
         // Replace each link of all P_x where x < i with a reverse link of inverted link weight in the original graph
-        let mut graph: HashMap<(u32, u32), i32> =
-            HashMap::from_iter(graph.iter().map(|edge| ((edge.from, edge.to), edge.weight)));
+        let mut graph = base.clone();
 
         for path in &paths {
             for link in path.windows(2) {
@@ -116,17 +255,10 @@ fn bhandari(_graph: &[Edge], start: &str, end: &str, k: usize) -> anyhow::Result
             }
         }
 
-        // Find the shortest path Pi from node s to node t
-        let successors = |current_node: &u32| {
-            graph
-                .iter()
-                .filter(|((from, _), _)| *current_node == *from)
-                .map(|((_, to), weight)| (*to, *weight))
-                .collect::<Vec<_>>()
-        };
-        let (shortest_path, _cost) =
-            dijkstra(&start, successors, |current_node| *current_node == end)
-                .context("this graph doesn't contain such path")?;
+        // Find the shortest path Pi from node s to node t. The reversed arcs above
+        // have negative weights, so Dijkstra is no longer valid here and we fall back
+        // to Bellman-Ford.
+        let shortest_path = bellman_ford(&graph, n, start, end)?;
 
         paths.push(shortest_path);
 
@@ -181,17 +313,375 @@ fn bhandari(_graph: &[Edge], start: &str, end: &str, k: usize) -> anyhow::Result
             .collect::<Vec<_>>();
     }
 
-    // restore original node names
-    let paths: Vec<Vec<String>> = paths
+    Ok(paths
+        .into_iter()
+        .map(|path| {
+            let cost = path
+                .windows(2)
+                .map(|link| base[&(link[0], link[1])])
+                .sum();
+            let path = path.into_iter().map(|node| g.from_index(node)).collect();
+            (path, cost)
+        })
+        .collect())
+}
+
+/// Find the `k` shortest *simple* paths from `start` to `end` with Yen's algorithm.
+/// Unlike Bhandari these may share links. Each path is returned as its list of node
+/// names, without its cost.
+fn yen(graph_in: &[Edge], start: &str, end: &str, k: usize) -> anyhow::Result<Vec<Vec<String>>> {
+    let (graph, indices) = build_graph(graph_in);
+
+    let start = *indices
+        .get(start)
+        .context("this graph doesn't contain such path")?;
+    let end = *indices
+        .get(end)
+        .context("this graph doesn't contain such path")?;
+
+    let paths = yen_generic(&graph, start, end, k)?;
+
+    Ok(paths
         .into_iter()
         .map(|path| {
             path.into_iter()
-                .map(|node| nodes_indices_to_names[node as usize].to_string())
+                .map(|node| graph[node].to_string())
+                .collect()
+        })
+        .collect())
+}
+
+/// Yen's k-shortest-paths over any petgraph-style graph with `i32` edge weights.
+fn yen_generic<G>(
+    g: G,
+    start: G::NodeId,
+    end: G::NodeId,
+    k: usize,
+) -> anyhow::Result<Vec<Vec<G::NodeId>>>
+where
+    G: IntoEdges + NodeIndexable + Data<EdgeWeight = i32>,
+{
+    if k == 0 {
+        return Ok(Vec::new());
+    }
+
+    let n = g.node_bound();
+    let start = g.to_index(start);
+    let end = g.to_index(end);
+
+    // snapshot adjacency and weights keyed by node index
+    let mut adjacency: Vec<Vec<(usize, i32)>> = vec![Vec::new(); n];
+    let mut weights: HashMap<(usize, usize), i32> = HashMap::new();
+    for (node, out_links) in adjacency.iter_mut().enumerate() {
+        for edge in g.edges(g.from_index(node)) {
+            let to = g.to_index(edge.target());
+            let weight = *edge.weight();
+            out_links.push((to, weight));
+            weights.insert((node, to), weight);
+        }
+    }
+
+    let path_cost = |path: &[usize]| -> i32 {
+        path.windows(2)
+            .map(|link| weights[&(link[0], link[1])])
+            .sum()
+    };
+
+    // shortest path over a subgraph with some links and nodes forbidden
+    let spur = |from: usize,
+                removed: &HashSet<(usize, usize)>,
+                blocked: &HashSet<usize>|
+     -> Option<Vec<usize>> {
+        let successors = |current: &usize| {
+            adjacency[*current]
+                .iter()
+                .filter(|(to, _)| !removed.contains(&(*current, *to)) && !blocked.contains(to))
+                .copied()
                 .collect::<Vec<_>>()
+        };
+        dijkstra(&from, successors, |node| *node == end).map(|(path, _)| path)
+    };
+
+    let first = spur(start, &HashSet::new(), &HashSet::new())
+        .context("this graph doesn't contain such path")?;
+
+    let mut accepted: Vec<Vec<usize>> = vec![first];
+    let mut candidates: BinaryHeap<Reverse<(i32, Vec<usize>)>> = BinaryHeap::new();
+    let mut seen: HashSet<Vec<usize>> = HashSet::new();
+
+    while accepted.len() < k {
+        let previous = accepted.last().unwrap().clone();
+
+        for j in 0..previous.len() - 1 {
+            let spur_node = previous[j];
+            let root = &previous[..=j];
+
+            // forbid every link that a found path takes out of this root prefix
+            let mut removed: HashSet<(usize, usize)> = HashSet::new();
+            for path in &accepted {
+                if path.len() > j && path[..=j] == *root {
+                    removed.insert((path[j], path[j + 1]));
+                }
+            }
+
+            // keep paths simple by forbidding the root's interior nodes
+            let blocked: HashSet<usize> = root[..j].iter().copied().collect();
+
+            if let Some(spur_path) = spur(spur_node, &removed, &blocked) {
+                let mut candidate = root[..j].to_vec();
+                candidate.extend(spur_path);
+
+                if seen.insert(candidate.clone()) {
+                    candidates.push(Reverse((path_cost(&candidate), candidate)));
+                }
+            }
+        }
+
+        match candidates.pop() {
+            Some(Reverse((_, candidate))) => accepted.push(candidate),
+            None => break,
+        }
+    }
+
+    Ok(accepted
+        .into_iter()
+        .map(|path| path.into_iter().map(|node| g.from_index(node)).collect())
+        .collect())
+}
+
+/// Connect a set of terminal nodes with a low-cost tree using the classic
+/// metric-closure 2-approximation: build the complete distance graph over the
+/// terminals with Dijkstra, take its MST, expand each MST edge back into its
+/// underlying shortest path, then MST the union to drop redundant links. Returns the
+/// chosen edges in the original graph.
+fn steiner_tree(graph_in: &[Edge], terminals: &[String]) -> anyhow::Result<Vec<Edge>> {
+    let (graph, indices) = build_graph(graph_in);
+    let n = graph.node_count();
+
+    // index-keyed adjacency and weights
+    let mut adjacency: Vec<Vec<(usize, i32)>> = vec![Vec::new(); n];
+    let mut weights: HashMap<(usize, usize), i32> = HashMap::new();
+    for edge in graph_in {
+        let from = indices[&*edge.from].index();
+        let to = indices[&*edge.to].index();
+        adjacency[from].push((to, edge.weight));
+        weights.insert((from, to), edge.weight);
+    }
+
+    let term_idx: Vec<usize> = terminals
+        .iter()
+        .map(|name| {
+            indices
+                .get(name.as_str())
+                .map(|node| node.index())
+                .with_context(|| format!("terminal {name} not present in graph"))
+        })
+        .collect::<anyhow::Result<_>>()?;
+
+    // shortest-path trees rooted at every terminal
+    let reach: HashMap<usize, HashMap<usize, (usize, i32)>> = term_idx
+        .iter()
+        .map(|&root| {
+            let successors = |node: &usize| adjacency[*node].clone();
+            (root, dijkstra_all(&root, successors))
         })
         .collect();
 
-    Ok(paths)
+    // complete distance graph over the terminals
+    let mut terminal_edges = Vec::new();
+    for (i, &a) in term_idx.iter().enumerate() {
+        for &b in &term_idx[i + 1..] {
+            let (_, cost) = reach[&a]
+                .get(&b)
+                .with_context(|| "terminals are not all connected")?;
+            terminal_edges.push((a, b, *cost));
+        }
+    }
+
+    let terminal_nodes: HashSet<usize> = term_idx.iter().copied().collect();
+    let terminal_mst = kruskal(&terminal_nodes, terminal_edges);
+
+    // expand each terminal-MST edge into the underlying shortest path
+    let mut expanded: Vec<(usize, usize, i32)> = Vec::new();
+    let mut expanded_nodes: HashSet<usize> = HashSet::new();
+    for (a, b, _) in terminal_mst {
+        let tree = &reach[&a];
+        let mut current = b;
+        while current != a {
+            let (parent, _) = tree[&current];
+            expanded.push((parent, current, weights[&(parent, current)]));
+            expanded_nodes.insert(parent);
+            expanded_nodes.insert(current);
+            current = parent;
+        }
+    }
+
+    // final MST over the union of expanded links drops redundant edges
+    let tree = kruskal(&expanded_nodes, expanded);
+
+    Ok(tree
+        .into_iter()
+        .map(|(from, to, weight)| Edge {
+            from: graph[NodeIndex::new(from)].clone(),
+            to: graph[NodeIndex::new(to)].clone(),
+            weight,
+        })
+        .collect())
+}
+
+/// Kruskal's minimum spanning tree over `edges` (`(from, to, weight)`), spanning the
+/// given node set. Returns the chosen edges.
+fn kruskal(nodes: &HashSet<usize>, mut edges: Vec<(usize, usize, i32)>) -> Vec<(usize, usize, i32)> {
+    fn find(parent: &mut HashMap<usize, usize>, x: usize) -> usize {
+        let p = parent[&x];
+        if p == x {
+            x
+        } else {
+            let root = find(parent, p);
+            parent.insert(x, root);
+            root
+        }
+    }
+
+    edges.sort_by_key(|&(_, _, weight)| weight);
+    let mut parent: HashMap<usize, usize> = nodes.iter().map(|&node| (node, node)).collect();
+
+    let mut chosen = Vec::new();
+    for (from, to, weight) in edges {
+        let root_from = find(&mut parent, from);
+        let root_to = find(&mut parent, to);
+        if root_from != root_to {
+            parent.insert(root_from, root_to);
+            chosen.push((from, to, weight));
+        }
+    }
+
+    chosen
+}
+
+/// Marker separating an original node name from its split `_in`/`_out` suffix.
+const SPLIT_MARKER: char = '\u{0}';
+
+/// Split every node except `start`/`end` into `v_in` and `v_out` joined by a
+/// zero-weight arc. Original incoming arcs are routed into `v_in` and outgoing arcs
+/// out of `v_out`, so that forbidding the internal arc forbids reuse of the node.
+fn split_internal_nodes(graph: &[Edge], start: &str, end: &str) -> Vec<Edge> {
+    let in_name = |node: &str| -> Arc<str> { format!("{node}{SPLIT_MARKER}in").into() };
+    let out_name = |node: &str| -> Arc<str> { format!("{node}{SPLIT_MARKER}out").into() };
+
+    let mut nodes: Vec<Arc<str>> = graph
+        .iter()
+        .flat_map(|edge| [edge.from.clone(), edge.to.clone()])
+        .collect();
+    nodes.sort();
+    nodes.dedup();
+
+    let mut edges = Vec::with_capacity(graph.len() + nodes.len());
+
+    // zero-weight internal arc for each split node
+    for node in &nodes {
+        if &**node != start && &**node != end {
+            edges.push(Edge {
+                from: in_name(node),
+                to: out_name(node),
+                weight: 0,
+            });
+        }
+    }
+
+    // reroute original arcs through the out/in endpoints
+    for edge in graph {
+        let from = if &*edge.from == start {
+            edge.from.clone()
+        } else {
+            out_name(&edge.from)
+        };
+        let to = if &*edge.to == end {
+            edge.to.clone()
+        } else {
+            in_name(&edge.to)
+        };
+        edges.push(Edge {
+            from,
+            to,
+            weight: edge.weight,
+        });
+    }
+
+    edges
+}
+
+/// Collapse a path over split nodes back to original node names, merging each
+/// consecutive `v_in`/`v_out` pair into a single `v`.
+fn collapse_split_path(path: Vec<String>) -> Vec<String> {
+    let mut collapsed: Vec<String> = Vec::with_capacity(path.len());
+    for node in path {
+        let original = node
+            .split(SPLIT_MARKER)
+            .next()
+            .unwrap_or(&node)
+            .to_string();
+        if collapsed.last() != Some(&original) {
+            collapsed.push(original);
+        }
+    }
+    collapsed
+}
+
+/// Shortest path from `start` to `end` over a graph that may contain negative-weight
+/// arcs, computed with Bellman-Ford. `n` is the number of nodes (node ids are the
+/// range `0..n`). Relaxes every arc `|V| - 1` times; a further relaxation on pass
+/// `|V|` signals a negative cycle, which Bhandari should never produce, so we return
+/// an error rather than loop forever.
+fn bellman_ford(
+    graph: &HashMap<(usize, usize), i32>,
+    n: usize,
+    start: usize,
+    end: usize,
+) -> anyhow::Result<Vec<usize>> {
+    let mut dist = vec![i32::MAX; n];
+    let mut pred: Vec<Option<usize>> = vec![None; n];
+    dist[start] = 0;
+
+    for pass in 0..n {
+        let mut relaxed = false;
+        for (&(from, to), &weight) in graph {
+            let du = dist[from];
+            if du == i32::MAX {
+                continue;
+            }
+            let candidate = du + weight;
+            if candidate < dist[to] {
+                dist[to] = candidate;
+                pred[to] = Some(from);
+                relaxed = true;
+            }
+        }
+
+        // on the |V|-th pass a relaxation means a negative cycle is reachable
+        if pass == n - 1 && relaxed {
+            anyhow::bail!("negative cycle detected during reversed-arc search");
+        }
+
+        if !relaxed {
+            break;
+        }
+    }
+
+    if dist[end] == i32::MAX {
+        anyhow::bail!("this graph doesn't contain such path");
+    }
+
+    // walk `pred` back from the target to reconstruct the path
+    let mut path = vec![end];
+    let mut current = end;
+    while current != start {
+        current = pred[current].context("this graph doesn't contain such path")?;
+        path.push(current);
+    }
+    path.reverse();
+
+    Ok(path)
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -235,3 +725,96 @@ fn parse_edge(line: &str, undirected: bool) -> anyhow::Result<Vec<Edge>> {
         vec![Edge { from, to, weight }]
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A-B-D and A-C-D, two equal-cost link-disjoint routes between A and D.
+    fn diamond() -> Vec<Edge> {
+        vec![
+            Edge {
+                from: "A".into(),
+                to: "B".into(),
+                weight: 1,
+            },
+            Edge {
+                from: "B".into(),
+                to: "D".into(),
+                weight: 1,
+            },
+            Edge {
+                from: "A".into(),
+                to: "C".into(),
+                weight: 1,
+            },
+            Edge {
+                from: "C".into(),
+                to: "D".into(),
+                weight: 1,
+            },
+        ]
+    }
+
+    #[test]
+    fn bhandari_finds_link_disjoint_paths() {
+        let result = bhandari(&diamond(), "A", "D", 2, false).unwrap();
+
+        let mut paths = result.paths();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                (vec!["A".to_string(), "B".to_string(), "D".to_string()], 2),
+                (vec!["A".to_string(), "C".to_string(), "D".to_string()], 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn bhandari_node_disjoint_collapses_split_nodes() {
+        let result = bhandari(&diamond(), "A", "D", 2, true).unwrap();
+
+        // the split `_in`/`_out` halves must be collapsed back to plain node names
+        let mut paths = result.paths();
+        paths.sort();
+        assert_eq!(
+            paths,
+            vec![
+                (vec!["A".to_string(), "B".to_string(), "D".to_string()], 2),
+                (vec!["A".to_string(), "C".to_string(), "D".to_string()], 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn yen_finds_k_shortest_simple_paths_in_cost_order() {
+        let edges = vec![
+            Edge {
+                from: "A".into(),
+                to: "B".into(),
+                weight: 1,
+            },
+            Edge {
+                from: "B".into(),
+                to: "C".into(),
+                weight: 1,
+            },
+            Edge {
+                from: "A".into(),
+                to: "C".into(),
+                weight: 5,
+            },
+        ];
+
+        let paths = yen(&edges, "A", "C", 2).unwrap();
+
+        assert_eq!(
+            paths,
+            vec![
+                vec!["A".to_string(), "B".to_string(), "C".to_string()],
+                vec!["A".to_string(), "C".to_string()],
+            ]
+        );
+    }
+}